@@ -0,0 +1,796 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::net::Ipv6Addr;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Default location of the config file when neither `--config` nor
+/// `MPC_CLI_CONFIG` is given.
+pub const DEFAULT_PATH: &str = "mpc-cli.toml";
+
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+impl LoadError {
+    /// Stable identifier for machine-readable output.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LoadError::Io { .. } => "config_io",
+            LoadError::Parse { .. } => "config_parse",
+        }
+    }
+}
+
+/// Node configuration as read from the TOML file, before any env or flag
+/// overrides are applied.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub party: PartyConfig,
+    pub transport: TransportConfig,
+    pub tls: Option<TlsConfig>,
+    pub keystore: KeystoreConfig,
+    #[serde(default)]
+    pub security: SecurityParams,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, SigningProfile>,
+}
+
+/// Identity of this node. Left out of a config file shared by the whole
+/// committee and supplied per party through [`Overrides`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartyConfig {
+    #[serde(default)]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransportConfig {
+    #[serde(default)]
+    pub listen: Option<String>,
+    #[serde(default)]
+    pub peers: Vec<PeerConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PeerConfig {
+    pub id: String,
+    pub endpoint: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+    pub ca: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeystoreConfig {
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct SecurityParams {
+    /// Number of parties that may be corrupted; `threshold + 1` parties sign.
+    pub threshold: usize,
+    /// Target security in bits; every curve and modulus must reach it.
+    pub level: usize,
+    pub paillier_bits: usize,
+    pub ntilde_bits: usize,
+}
+
+impl SecurityParams {
+    pub const MIN_MODULUS_BITS: usize = 2048;
+
+    /// Modulus size matching `level` bits of security, per NIST SP 800-57
+    /// Part 1 Table 2.
+    pub fn modulus_bits_for(level: usize) -> Option<usize> {
+        match level {
+            112 => Some(Self::MIN_MODULUS_BITS),
+            128 => Some(3072),
+            192 => Some(7680),
+            256 => Some(15360),
+            _ => None,
+        }
+    }
+
+    /// Refuses combinations where the moduli or any of the `curves` in use
+    /// fall short of `level`. Anything building SecurityParams outside of
+    /// [`Config::validate`] must call this before starting a ceremony.
+    pub fn check(&self, curves: impl IntoIterator<Item = Curve>) -> Result<(), Vec<Issue>> {
+        let mut issues = self.moduli_issues();
+        let mut seen = Vec::new();
+        for curve in curves {
+            if seen.contains(&curve) {
+                continue;
+            }
+            seen.push(curve);
+            if let Some(message) = self.curve_shortfall(curve) {
+                issues.push(Issue {
+                    field: "security.level".to_string(),
+                    message,
+                });
+            }
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Problems with `level` itself or with the modulus sizes it requires.
+    fn moduli_issues(&self) -> Vec<Issue> {
+        let Some(required) = Self::modulus_bits_for(self.level) else {
+            return vec![Issue {
+                field: "security.level".to_string(),
+                message: format!("{} is not one of 112, 128, 192, 256", self.level),
+            }];
+        };
+        [
+            ("paillier_bits", "Paillier", self.paillier_bits),
+            ("ntilde_bits", "NTilde", self.ntilde_bits),
+        ]
+        .into_iter()
+        .filter(|&(_, _, bits)| bits < required)
+        .map(|(name, label, bits)| Issue {
+            field: format!("security.{name}"),
+            message: format!(
+                "{bits}-bit {label} insufficient at {}-bit security, needs {required}",
+                self.level
+            ),
+        })
+        .collect()
+    }
+
+    /// Why `curve` cannot reach `level`, if it cannot. An unknown level is
+    /// already reported by [`Self::moduli_issues`].
+    fn curve_shortfall(&self, curve: Curve) -> Option<String> {
+        Self::modulus_bits_for(self.level)?;
+        (curve.security_bits() < self.level).then(|| {
+            format!(
+                "{curve} provides only {}-bit security, below the {}-bit level",
+                curve.security_bits(),
+                self.level
+            )
+        })
+    }
+}
+
+impl Default for SecurityParams {
+    fn default() -> Self {
+        Self {
+            threshold: 1,
+            level: 112,
+            paillier_bits: Self::MIN_MODULUS_BITS,
+            ntilde_bits: Self::MIN_MODULUS_BITS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SigningProfile {
+    pub chain: Chain,
+    pub curve: Curve,
+    pub derivation_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Chain {
+    Bitcoin,
+    Ethereum,
+    Cosmos,
+    Solana,
+}
+
+impl Chain {
+    fn supports(self, curve: Curve) -> bool {
+        match self {
+            Chain::Bitcoin | Chain::Ethereum => curve == Curve::Secp256k1,
+            Chain::Cosmos => true,
+            Chain::Solana => curve == Curve::Ed25519,
+        }
+    }
+}
+
+/// Spelled as in the config file.
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Chain::Bitcoin => "bitcoin",
+            Chain::Ethereum => "ethereum",
+            Chain::Cosmos => "cosmos",
+            Chain::Solana => "solana",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Curve {
+    Secp256k1,
+    Ed25519,
+}
+
+impl Curve {
+    fn security_bits(self) -> usize {
+        match self {
+            Curve::Secp256k1 | Curve::Ed25519 => 128,
+        }
+    }
+}
+
+/// Spelled as in the config file.
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Curve::Secp256k1 => "secp256k1",
+            Curve::Ed25519 => "ed25519",
+        })
+    }
+}
+
+/// Values taken from the environment or the command line, applied on top of
+/// the file so that a shared config can be reused across parties.
+#[derive(Debug, Default)]
+pub struct Overrides {
+    pub party_id: Option<String>,
+    pub listen: Option<String>,
+    pub keystore: Option<PathBuf>,
+}
+
+/// A single misconfiguration, located by its dotted path in the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Issue {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl Config {
+    /// Reads the file at `path`. Relative TLS and keystore paths in it are
+    /// taken relative to the file, not to the working directory.
+    pub fn load(path: &Path) -> Result<Self, LoadError> {
+        let text = fs::read_to_string(path).map_err(|source| LoadError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut config: Self = toml::from_str(&text).map_err(|source| LoadError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        config.resolve_paths(path.parent().unwrap_or(Path::new("")));
+        Ok(config)
+    }
+
+    fn resolve_paths(&mut self, base: &Path) {
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        };
+        resolve(&mut self.keystore.path);
+        if let Some(tls) = &mut self.tls {
+            for path in [&mut tls.cert, &mut tls.key, &mut tls.ca] {
+                resolve(path);
+            }
+        }
+    }
+
+    pub fn apply(&mut self, overrides: Overrides) {
+        if let Some(id) = overrides.party_id {
+            self.party.id = Some(id);
+        }
+        if let Some(listen) = overrides.listen {
+            self.transport.listen = Some(listen);
+        }
+        if let Some(path) = overrides.keystore {
+            self.keystore.path = path;
+        }
+    }
+
+    /// Number of parties in the committee, this node included.
+    pub fn parties(&self) -> usize {
+        self.transport.peers.len() + 1
+    }
+
+    /// Checks everything that can be checked without talking to peers and
+    /// returns every problem found rather than stopping at the first.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut issue = |field: &str, message: String| {
+            issues.push(Issue {
+                field: field.to_string(),
+                message,
+            })
+        };
+
+        match &self.party.id {
+            None => issue(
+                "party.id",
+                "not set in file, MPC_CLI_PARTY_ID or --party-id".to_string(),
+            ),
+            Some(id) if id.trim().is_empty() => issue("party.id", "must not be empty".to_string()),
+            Some(_) => {}
+        }
+
+        match &self.transport.listen {
+            None => issue(
+                "transport.listen",
+                "not set in file, MPC_CLI_LISTEN or --listen".to_string(),
+            ),
+            Some(listen) => {
+                if let Err(e) = check_endpoint(listen, false) {
+                    issue("transport.listen", e);
+                }
+            }
+        }
+        let mut seen = BTreeSet::new();
+        for (i, peer) in self.transport.peers.iter().enumerate() {
+            let field = format!("transport.peers[{i}]");
+            if peer.id.trim().is_empty() {
+                issue(&format!("{field}.id"), "must not be empty".to_string());
+            } else if self.party.id.as_deref() == Some(peer.id.as_str()) {
+                issue(
+                    &format!("{field}.id"),
+                    format!("'{}' is this party's own id", peer.id),
+                );
+            } else if !seen.insert(peer.id.as_str()) {
+                issue(
+                    &format!("{field}.id"),
+                    format!("duplicate peer id '{}'", peer.id),
+                );
+            }
+            if let Err(e) = check_endpoint(&peer.endpoint, true) {
+                issue(&format!("{field}.endpoint"), e);
+            }
+        }
+
+        if let Some(tls) = &self.tls {
+            for (name, path) in [("cert", &tls.cert), ("key", &tls.key), ("ca", &tls.ca)] {
+                if !path.is_file() {
+                    issue(
+                        &format!("tls.{name}"),
+                        format!("{} is not a file", path.display()),
+                    );
+                } else if let Err(e) = File::open(path) {
+                    issue(
+                        &format!("tls.{name}"),
+                        format!("{} cannot be read: {e}", path.display()),
+                    );
+                }
+            }
+        }
+
+        let keystore = &self.keystore.path;
+        if keystore.exists() {
+            if !keystore.is_dir() {
+                issue(
+                    "keystore.path",
+                    format!("{} is not a directory", keystore.display()),
+                );
+            }
+        } else {
+            match keystore.parent() {
+                Some(parent) if parent.as_os_str().is_empty() || parent.is_dir() => {}
+                _ => issue(
+                    "keystore.path",
+                    format!("parent of {} does not exist", keystore.display()),
+                ),
+            }
+        }
+
+        let security = &self.security;
+        let n = self.parties();
+        if security.threshold == 0 || security.threshold >= n {
+            issue(
+                "security.threshold",
+                format!(
+                    "must satisfy 0 < t < n, got t = {} with n = {n}",
+                    security.threshold
+                ),
+            );
+        }
+        // Curves are checked per profile below so that issues name the profile.
+        if let Err(weak) = security.check([]) {
+            for Issue { field, message } in weak {
+                issue(&field, message);
+            }
+        }
+
+        for (name, profile) in &self.profiles {
+            if let Some(message) = security.curve_shortfall(profile.curve) {
+                issue(&format!("profiles.{name}.curve"), message);
+            }
+            if !profile.chain.supports(profile.curve) {
+                issue(
+                    &format!("profiles.{name}.curve"),
+                    format!("{} cannot sign for {}", profile.curve, profile.chain),
+                );
+            }
+            if let Some(path) = &profile.derivation_path {
+                if let Err(e) = check_derivation_path(path, profile.curve) {
+                    issue(&format!("profiles.{name}.derivation_path"), e);
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Accepts `host:port` where an IPv6 host must be bracketed, as in
+/// `[::1]:7000`. Port 0 only makes sense for listening, so it is rejected
+/// when the endpoint is one we `dial`.
+fn check_endpoint(endpoint: &str, dial: bool) -> Result<(), String> {
+    let (host, port) = endpoint
+        .rsplit_once(':')
+        .ok_or_else(|| format!("'{endpoint}' is not of the form host:port"))?;
+    if host.is_empty() {
+        return Err(format!("'{endpoint}' has no host"));
+    }
+    if let Some(inner) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        inner
+            .parse::<Ipv6Addr>()
+            .map_err(|_| format!("'{endpoint}' has an invalid IPv6 address"))?;
+    } else if host.contains([':', '[', ']']) {
+        return Err(format!("'{endpoint}' must bracket its IPv6 address"));
+    }
+    match port.parse::<u16>() {
+        Ok(0) if dial => Err(format!("'{endpoint}' has port 0, which cannot be dialed")),
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{endpoint}' has an invalid port")),
+    }
+}
+
+/// Accepts BIP32 style paths such as `m/44'/60'/0'/0`. Ed25519 keys follow
+/// SLIP-10, which only defines hardened derivation.
+fn check_derivation_path(path: &str, curve: Curve) -> Result<(), String> {
+    let mut parts = path.split('/');
+    if parts.next() != Some("m") {
+        return Err(format!("'{path}' must start with 'm'"));
+    }
+    for part in parts {
+        let hardened = part.strip_suffix('\'').or_else(|| part.strip_suffix('h'));
+        match hardened.unwrap_or(part).parse::<u32>() {
+            Ok(i) if i < 1 << 31 => {}
+            _ => return Err(format!("'{part}' is not a valid path component")),
+        }
+        if hardened.is_none() && curve == Curve::Ed25519 {
+            return Err(format!(
+                "'{part}' must be hardened, ed25519 only supports hardened derivation"
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const CLEAN: &str = r#"
+[party]
+id = "alice"
+
+[transport]
+listen = "[::1]:7000"
+peers = [
+    { id = "bob", endpoint = "bob.example:7000" },
+    { id = "carol", endpoint = "10.0.0.3:7000" },
+]
+
+[keystore]
+path = "keystore"
+
+[profiles.eth]
+chain = "ethereum"
+curve = "secp256k1"
+derivation_path = "m/44'/60'/0'/0"
+"#;
+
+    /// Parses `text` as if it were loaded from a fresh temporary directory,
+    /// which must outlive any validation of the result.
+    fn parse(text: &str) -> (TempDir, Config) {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config: Config = toml::from_str(text).unwrap();
+        config.resolve_paths(dir.path());
+        (dir, config)
+    }
+
+    fn fields(text: &str) -> Vec<String> {
+        let (_dir, config) = parse(text);
+        config
+            .validate()
+            .into_iter()
+            .map(|issue| issue.field)
+            .collect()
+    }
+
+    #[test]
+    fn clean_config_has_no_issues() {
+        let (_dir, config) = parse(CLEAN);
+        assert_eq!(config.validate(), vec![]);
+    }
+
+    #[test]
+    fn relative_paths_follow_the_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("ks")).unwrap();
+        for name in ["cert.pem", "key.pem", "ca.pem"] {
+            fs::write(dir.path().join(name), "").unwrap();
+        }
+        // Present only in the working directory, so it must not satisfy
+        // `sub/ks` below.
+        fs::create_dir(elsewhere.path().join("sub")).unwrap();
+        let text = format!(
+            "{}\n[tls]\ncert = \"cert.pem\"\nkey = \"key.pem\"\nca = \"ca.pem\"\n",
+            CLEAN.replace("path = \"keystore\"", "path = \"ks\"")
+        );
+        let file = dir.path().join("c.toml");
+        fs::write(&file, &text).unwrap();
+        fs::write(
+            dir.path().join("d.toml"),
+            text.replace("path = \"ks\"", "path = \"sub/ks\""),
+        )
+        .unwrap();
+
+        let cwd = env::current_dir().unwrap();
+        env::set_current_dir(elsewhere.path()).unwrap();
+        let config = Config::load(&file);
+        let nested = Config::load(&dir.path().join("d.toml"));
+        env::set_current_dir(cwd).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.keystore.path, dir.path().join("ks"));
+        assert_eq!(config.tls.as_ref().unwrap().ca, dir.path().join("ca.pem"));
+        assert_eq!(config.validate(), vec![]);
+        let fields: Vec<_> = nested
+            .unwrap()
+            .validate()
+            .into_iter()
+            .map(|issue| issue.field)
+            .collect();
+        assert_eq!(fields, ["keystore.path"]);
+    }
+
+    #[test]
+    fn identity_may_come_from_overrides() {
+        let shared = CLEAN
+            .replace("id = \"alice\"", "")
+            .replace("listen = \"[::1]:7000\"", "");
+        let (_dir, mut config) = parse(&shared);
+        assert_eq!(
+            config.validate(),
+            vec![
+                Issue {
+                    field: "party.id".to_string(),
+                    message: "not set in file, MPC_CLI_PARTY_ID or --party-id".to_string(),
+                },
+                Issue {
+                    field: "transport.listen".to_string(),
+                    message: "not set in file, MPC_CLI_LISTEN or --listen".to_string(),
+                },
+            ]
+        );
+
+        config.apply(Overrides {
+            party_id: Some("alice".to_string()),
+            listen: Some("0.0.0.0:7000".to_string()),
+            keystore: None,
+        });
+        assert_eq!(config.validate(), vec![]);
+    }
+
+    #[test]
+    fn rejects_empty_self_and_duplicate_peer_ids() {
+        let text = CLEAN.replace(
+            r#"{ id = "carol", endpoint = "10.0.0.3:7000" },"#,
+            r#"{ id = " ", endpoint = "10.0.0.3:7000" },
+    { id = "alice", endpoint = "10.0.0.4:7000" },
+    { id = "bob", endpoint = "10.0.0.5:7000" },"#,
+        );
+        assert_eq!(
+            fields(&text),
+            [
+                "transport.peers[1].id",
+                "transport.peers[2].id",
+                "transport.peers[3].id",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_endpoints() {
+        let text = CLEAN
+            .replace("[::1]:7000", "fe80::1")
+            .replace("bob.example:7000", "a:b:80")
+            .replace("10.0.0.3:7000", "10.0.0.2:0");
+        assert_eq!(
+            fields(&text),
+            [
+                "transport.listen",
+                "transport.peers[0].endpoint",
+                "transport.peers[1].endpoint",
+            ]
+        );
+
+        for endpoint in ["bob.example", ":7000", "[::1", "[zz]:7000", "bob:70000"] {
+            let text = CLEAN.replace("bob.example:7000", endpoint);
+            assert_eq!(fields(&text), ["transport.peers[0].endpoint"], "{endpoint}");
+        }
+    }
+
+    #[test]
+    fn listen_may_use_port_zero() {
+        assert_eq!(
+            fields(&CLEAN.replace("[::1]:7000", "[::]:0")),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn threshold_must_be_below_party_count() {
+        for t in [0, 3, 4] {
+            let text = format!("{CLEAN}\n[security]\nthreshold = {t}\n");
+            assert_eq!(fields(&text), ["security.threshold"], "t = {t}");
+        }
+        let text = format!("{CLEAN}\n[security]\nthreshold = 2\n");
+        assert_eq!(fields(&text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_missing_keystore_parent() {
+        let text = CLEAN.replace("path = \"keystore\"", "path = \"missing/keystore\"");
+        assert_eq!(fields(&text), ["keystore.path"]);
+    }
+
+    #[test]
+    fn rejects_curve_the_chain_cannot_use() {
+        let text = CLEAN.replace("chain = \"ethereum\"", "chain = \"solana\"");
+        let (_dir, config) = parse(&text);
+        assert_eq!(
+            config.validate(),
+            vec![Issue {
+                field: "profiles.eth.curve".to_string(),
+                message: "secp256k1 cannot sign for solana".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_moduli_below_level() {
+        let params = SecurityParams {
+            level: 192,
+            ..SecurityParams::default()
+        };
+        let issues = params.check([]).unwrap_err();
+        assert_eq!(
+            issues,
+            vec![
+                Issue {
+                    field: "security.paillier_bits".to_string(),
+                    message: "2048-bit Paillier insufficient at 192-bit security, needs 7680"
+                        .to_string(),
+                },
+                Issue {
+                    field: "security.ntilde_bits".to_string(),
+                    message: "2048-bit NTilde insufficient at 192-bit security, needs 7680"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_curve_below_level() {
+        let params = SecurityParams {
+            level: 256,
+            paillier_bits: 15360,
+            ntilde_bits: 15360,
+            ..SecurityParams::default()
+        };
+        assert_eq!(
+            params.check([Curve::Secp256k1, Curve::Secp256k1]),
+            Err(vec![Issue {
+                field: "security.level".to_string(),
+                message: "secp256k1 provides only 128-bit security, below the 256-bit level"
+                    .to_string(),
+            }])
+        );
+        assert_eq!(params.check([]), Ok(()));
+    }
+
+    #[test]
+    fn weak_curves_are_reported_per_profile() {
+        let text = format!(
+            "{CLEAN}\n[profiles.sol]\nchain = \"solana\"\ncurve = \"ed25519\"\n\n[security]\nlevel = 192\npaillier_bits = 7680\nntilde_bits = 7680\n"
+        );
+        let (_dir, config) = parse(&text);
+        assert_eq!(
+            config.validate(),
+            vec![
+                Issue {
+                    field: "profiles.eth.curve".to_string(),
+                    message: "secp256k1 provides only 128-bit security, below the 192-bit level"
+                        .to_string(),
+                },
+                Issue {
+                    field: "profiles.sol.curve".to_string(),
+                    message: "ed25519 provides only 128-bit security, below the 192-bit level"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_level() {
+        let text = format!("{CLEAN}\n[security]\nlevel = 100\n");
+        assert_eq!(fields(&text), ["security.level"]);
+    }
+
+    #[test]
+    fn default_params_pass_with_both_curves() {
+        assert_eq!(
+            SecurityParams::default().check([Curve::Secp256k1, Curve::Ed25519]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn ed25519_paths_must_be_fully_hardened() {
+        let sol = format!(
+            "{CLEAN}\n[profiles.sol]\nchain = \"solana\"\ncurve = \"ed25519\"\nderivation_path = \"m/44'/501'/0'/0\"\n"
+        );
+        assert_eq!(fields(&sol), ["profiles.sol.derivation_path"]);
+        let sol = sol.replace("m/44'/501'/0'/0", "m/44'/501'/0'/0h");
+        assert_eq!(fields(&sol), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_malformed_derivation_paths() {
+        for path in ["m/", "m/2147483648", "x/0", "m/0''", "M/0"] {
+            let text = CLEAN.replace("m/44'/60'/0'/0", path);
+            assert_eq!(fields(&text), ["profiles.eth.derivation_path"], "{path}");
+        }
+        for path in ["m", "m/2147483647'", "m/44h/0h"] {
+            let text = CLEAN.replace("m/44'/60'/0'/0", path);
+            assert_eq!(fields(&text), Vec::<String>::new(), "{path}");
+        }
+    }
+}
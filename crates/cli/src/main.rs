@@ -1,3 +1,154 @@
-fn main() {
-    println!("Hello, world!");
+mod config;
+mod output;
+
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use serde_json::json;
+
+use crate::config::{Config, Overrides};
+use crate::output::Format;
+
+/// Run and inspect a threshold-signing (MPC) node
+#[derive(Debug, Parser)]
+#[command(name = "mpc-cli", version)]
+struct Cli {
+    #[command(flatten)]
+    global: GlobalArgs,
+    #[command(subcommand)]
+    command: Command,
+}
+
+// Settings layered on top of the config file; flags win over the
+// environment, which wins over the file. Kept as a plain comment because
+// clap would otherwise use it as the program description.
+#[derive(Debug, Args)]
+struct GlobalArgs {
+    /// Path to the TOML config file
+    #[arg(long, global = true, env = "MPC_CLI_CONFIG", default_value = config::DEFAULT_PATH)]
+    config: PathBuf,
+    /// Override `party.id`
+    #[arg(long, global = true, env = "MPC_CLI_PARTY_ID")]
+    party_id: Option<String>,
+    /// Override `transport.listen`
+    #[arg(long, global = true, env = "MPC_CLI_LISTEN")]
+    listen: Option<String>,
+    /// Override `keystore.path`
+    #[arg(long, global = true, env = "MPC_CLI_KEYSTORE")]
+    keystore: Option<PathBuf>,
+    /// Output format for command results
+    #[arg(
+        long,
+        global = true,
+        env = "MPC_CLI_OUTPUT",
+        value_enum,
+        default_value_t
+    )]
+    output: Format,
+}
+
+impl GlobalArgs {
+    fn load_config(&self) -> Result<Config, config::LoadError> {
+        let mut config = Config::load(&self.config)?;
+        config.apply(Overrides {
+            party_id: self.party_id.clone(),
+            listen: self.listen.clone(),
+            keystore: self.keystore.clone(),
+        });
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Inspect the node configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Print a shell completion script to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    /// Check the configuration for mistakes before a ceremony starts
+    Validate,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<OsString> = env::args_os().collect();
+    let cli = match Cli::try_parse_from(&args) {
+        Ok(cli) => cli,
+        Err(e) => return usage_error(e, Format::from_raw_args(&args)),
+    };
+    match cli.command {
+        Command::Config(ConfigCommand::Validate) => validate(&cli.global),
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "mpc-cli", &mut io::stdout());
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// Leaves help, version and text-mode errors to clap, but keeps JSON mode
+/// callers on a single stdout object even when the arguments are wrong.
+fn usage_error(e: clap::Error, format: Format) -> ExitCode {
+    if format == Format::Text || !e.use_stderr() {
+        e.exit();
+    }
+    // clap renders "error: ..." followed by a blank line and the usage text;
+    // the first paragraph is the actual complaint.
+    let message = match e.kind() {
+        ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => {
+            "a subcommand is required".to_string()
+        }
+        _ => e
+            .to_string()
+            .lines()
+            .take_while(|line| !line.trim().is_empty())
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+    format.error("usage", message.strip_prefix("error: ").unwrap_or(&message));
+    ExitCode::from(e.exit_code() as u8)
+}
+
+fn validate(global: &GlobalArgs) -> ExitCode {
+    let format = global.output;
+    let config = match global.load_config() {
+        Ok(config) => config,
+        Err(e) => {
+            format.error(e.code(), &e.to_string());
+            return ExitCode::FAILURE;
+        }
+    };
+    let issues = config.validate();
+    match format {
+        Format::Text if issues.is_empty() => println!("{}: ok", global.config.display()),
+        Format::Text => {
+            for issue in &issues {
+                eprintln!("error: {issue}");
+            }
+            eprintln!("{} problem(s) found", issues.len());
+        }
+        Format::Json => output::print_json(&json!({
+            "ok": issues.is_empty(),
+            "config": global.config.display().to_string(),
+            "issues": issues,
+        })),
+    }
+    if issues.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
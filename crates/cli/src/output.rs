@@ -0,0 +1,98 @@
+use std::env;
+use std::ffi::OsString;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+/// How command results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per invocation, for orchestration scripts
+    Json,
+}
+
+impl Format {
+    /// Picks the format from raw arguments and the environment, for errors
+    /// raised before clap has finished parsing them.
+    pub fn from_raw_args(args: &[OsString]) -> Self {
+        let mut value = None;
+        let mut args = args.iter().map(|arg| arg.to_str());
+        while let Some(arg) = args.next() {
+            match arg {
+                Some("--") => break,
+                // A non-UTF-8 value is no format at all, so it must not
+                // undo an earlier valid flag.
+                Some("--output") => {
+                    if let Some(Some(next)) = args.next() {
+                        value = Some(next);
+                    }
+                }
+                Some(arg) => value = arg.strip_prefix("--output=").or(value),
+                None => {}
+            }
+        }
+        let env = env::var("MPC_CLI_OUTPUT").ok();
+        match value.or(env.as_deref()) {
+            Some(value) => Self::from_str(value, true).unwrap_or_default(),
+            None => Self::default(),
+        }
+    }
+
+    /// Reports a failure that prevented the command from producing a result.
+    /// In JSON mode the error goes to stdout so callers only parse one stream.
+    pub fn error(self, code: &str, message: &str) {
+        match self {
+            Format::Text => eprintln!("error: {message}"),
+            Format::Json => print_json(&json!({
+                "ok": false,
+                "error": { "code": code, "message": message },
+            })),
+        }
+    }
+}
+
+pub fn print_json(value: &Value) {
+    println!("{value}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(args: &[&str]) -> Format {
+        let args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        Format::from_raw_args(&args)
+    }
+
+    #[test]
+    fn raw_args_pick_the_last_output_flag() {
+        assert_eq!(
+            format(&["mpc-cli", "--output", "json", "config"]),
+            Format::Json
+        );
+        assert_eq!(
+            format(&["mpc-cli", "config", "--output=JSON"]),
+            Format::Json
+        );
+        assert_eq!(
+            format(&["mpc-cli", "--output=json", "--output", "text"]),
+            Format::Text
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+
+            let args = vec![
+                OsString::from("mpc-cli"),
+                OsString::from("--output=json"),
+                OsString::from("--output"),
+                OsString::from_vec(vec![0xff]),
+                OsString::from("config"),
+            ];
+            assert_eq!(Format::from_raw_args(&args), Format::Json);
+        }
+    }
+}